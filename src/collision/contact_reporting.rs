@@ -15,6 +15,7 @@ use bevy::prelude::*;
 /// - [`Collision`]
 /// - [`CollisionStarted`]
 /// - [`CollisionEnded`]
+/// - [`ContactForceEvent`]
 ///
 /// You can listen to them with normal event readers:
 ///
@@ -40,18 +41,73 @@ use bevy::prelude::*;
 ///     }
 /// }
 /// ```
-pub struct ContactReportingPlugin;
+///
+/// # Observer Events
+///
+/// In addition to the buffered events above, [`OnCollisionStart`], [`OnCollisionEnd`], and
+/// [`OnContact`] are triggered directly on the colliding entities, so they can be listened to
+/// with an observer attached to a specific entity instead of filtering a global event stream.
+/// They are gated by the same [`ActiveCollisionEvents`] flags as their buffered counterparts.
+/// See [`OnCollisionStart`] for an example.
+///
+/// # Default Active Events
+///
+/// By default, every entity with a collider opts in to every collision event. In scenes with
+/// many passive colliders, you can switch the default to off with
+/// [`ContactReportingPlugin::default_active_events`] and then opt specific entities back in
+/// with the [`ActiveCollisionEvents`] component:
+///
+/// ```no_run
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+/// use bevy::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             PhysicsPlugins::default().build().disable::<ContactReportingPlugin>(),
+///         ))
+///         .add_plugins(ContactReportingPlugin {
+///             default_active_events: ActiveCollisionEvents::empty(),
+///         })
+///         .run();
+/// }
+/// ```
+pub struct ContactReportingPlugin {
+    /// The [`ActiveCollisionEvents`] used as the default for entities that don't have the
+    /// component themselves.
+    ///
+    /// Defaults to [`ActiveCollisionEvents::all()`] for backwards compatibility.
+    pub default_active_events: ActiveCollisionEvents,
+}
+
+impl Default for ContactReportingPlugin {
+    fn default() -> Self {
+        Self {
+            default_active_events: ActiveCollisionEvents::all(),
+        }
+    }
+}
 
 impl Plugin for ContactReportingPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<Collision>()
             .add_event::<CollisionStarted>()
-            .add_event::<CollisionEnded>();
+            .add_event::<CollisionEnded>()
+            .add_event::<ContactForceEvent>()
+            .init_resource::<ContactForceEventThreshold>()
+            .insert_resource(self.default_active_events);
 
         let physics_schedule = app
             .get_schedule_mut(PhysicsSchedule)
             .expect("add PhysicsSchedule first");
 
+        physics_schedule.configure_sets(
+            PreSolveContactsSet
+                .after(PhysicsStepSet::NarrowPhase)
+                .before(PhysicsStepSet::Solver),
+        );
         physics_schedule.add_systems(report_contacts.in_set(PhysicsStepSet::ReportContacts));
     }
 
@@ -61,6 +117,42 @@ impl Plugin for ContactReportingPlugin {
     }
 }
 
+/// The [`SystemSet`] in which user systems can mutate narrow-phase [`Contacts`] before the
+/// constraint solver consumes them for the step.
+///
+/// This set is constrained to run after [`PhysicsStepSet::NarrowPhase`] and before
+/// [`PhysicsStepSet::Solver`], giving systems in it read-and-write access to each pair's
+/// [`Contacts`] through the [`Collisions`] resource rather than a read-only `EventReader`. A
+/// common use is flipping [`Contacts::during_current_frame`] to `false` to disable a pair for
+/// this step, or editing per-manifold normal and friction data, which unlocks gameplay
+/// collision filtering (one-way platforms, conditional pass-through, per-pair material
+/// overrides) that can't be expressed with static [`CollisionLayers`] alone.
+///
+/// ```no_run
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+/// use bevy::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+///         .add_systems(
+///             PhysicsSchedule,
+///             one_way_platforms.in_set(PreSolveContactsSet),
+///         )
+///         .run();
+/// }
+///
+/// fn one_way_platforms(mut collisions: ResMut<Collisions>) {
+///     for contacts in collisions.get_internal_mut().values_mut() {
+///         // Disable the pair for this step based on custom gameplay logic.
+///         contacts.during_current_frame = false;
+///     }
+/// }
+/// ```
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PreSolveContactsSet;
+
 /// A [collision event](ContactReportingPlugin#collision-events)
 /// that is sent for each collision.
 ///
@@ -154,25 +246,230 @@ pub struct CollisionStarted(pub Entity, pub Entity);
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollisionEnded(pub Entity, pub Entity);
 
+/// A targeted collision event triggered on an entity when it starts colliding with another
+/// entity.
+///
+/// Unlike [`CollisionStarted`], which is read from a global `EventReader`, this is triggered
+/// directly on the colliding entities with [`Commands::trigger_targets`], so it can be
+/// listened to with an observer attached to a specific entity:
+///
+/// ```no_run
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+///
+/// fn setup(mut commands: Commands, player: Entity) {
+///     commands.entity(player).observe(
+///         |trigger: Trigger<OnCollisionStart>| {
+///             println!("Player started colliding with {}", trigger.event().collider);
+///         },
+///     );
+/// }
+/// ```
+#[derive(Event, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct OnCollisionStart {
+    /// The other entity involved in the collision.
+    pub collider: Entity,
+    /// The contact data for the collision.
+    pub contacts: Contacts,
+}
+
+/// A targeted collision event triggered on an entity when it stops colliding with another
+/// entity.
+///
+/// See [`OnCollisionStart`] for how to listen to this with an observer.
+#[derive(Event, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct OnCollisionEnd {
+    /// The other entity that was involved in the collision.
+    pub collider: Entity,
+    /// The contact data from the last frame the pair was in contact.
+    pub contacts: Contacts,
+}
+
+/// A targeted collision event triggered on an entity for every contacting pair it is part of,
+/// every frame the pair is in contact.
+///
+/// See [`OnCollisionStart`] for how to listen to this with an observer.
+#[derive(Event, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct OnContact {
+    /// The other entity involved in the contact.
+    pub collider: Entity,
+    /// The contact data for the pair.
+    pub contacts: Contacts,
+}
+
+/// A [collision event](ContactReportingPlugin#collision-events) that is sent when the largest
+/// force at any single contact point between two colliding entities exceeds the
+/// [`ContactForceEventThreshold`].
+///
+/// This is useful for reacting to how *hard* two bodies hit each other, for example to play
+/// impact sounds, apply damage, or break objects, rather than just reacting to overlap.
+///
+/// # Example
+///
+/// ```no_run
+#[cfg_attr(feature = "2d", doc = "use avian2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use avian3d::prelude::*;")]
+/// use bevy::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+///         .add_systems(Update, print_contact_forces)
+///         .run();
+/// }
+///
+/// fn print_contact_forces(mut contact_force_ev_reader: EventReader<ContactForceEvent>) {
+///     for event in contact_force_ev_reader.read() {
+///         println!(
+///             "Entities {} and {} collided with a force of {}",
+///             event.entity1,
+///             event.entity2,
+///             event.max_force_magnitude,
+///         );
+///     }
+/// }
+/// ```
+#[derive(Event, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactForceEvent {
+    /// The first entity in the collision.
+    pub entity1: Entity,
+    /// The second entity in the collision.
+    pub entity2: Entity,
+    /// The total contact force acting on `entity1`, pointing away from `entity2`.
+    pub total_force: Vector,
+    /// The magnitude of the largest force among the contact points of all manifolds of the pair.
+    pub max_force_magnitude: Scalar,
+}
+
+/// The force magnitude that a contact must exceed for a [`ContactForceEvent`] to be sent for
+/// it.
+///
+/// Used both as a global default (as a resource) and as a per-entity override (as a
+/// component). If either entity in a contact pair has a [`ContactForceEventThreshold`]
+/// component, the lower of the two overrides is used; otherwise the resource's value applies.
+///
+/// The default is `Scalar::MAX`, meaning contact force events are off by default.
+#[derive(Component, Resource, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactForceEventThreshold(pub Scalar);
+
+impl Default for ContactForceEventThreshold {
+    fn default() -> Self {
+        Self(Scalar::MAX)
+    }
+}
+
+bitflags::bitflags! {
+    /// Flags for enabling or disabling the [collision events](ContactReportingPlugin#collision-events)
+    /// sent for a specific entity.
+    ///
+    /// Used both as a global default (as a resource) and as a per-entity override (as a
+    /// component). A given event is only sent for a pair if at least one of the two entities
+    /// has the corresponding flag set, falling back to the [`ActiveCollisionEvents`] resource
+    /// for entities without the component.
+    ///
+    /// This is useful in scenes with many passive colliders where only a handful of entities
+    /// care about collision events: by default every collider opts in to every event, which is
+    /// wasteful if most of them are never read. Insert this component on the entities that
+    /// actually need events, and set [`ContactReportingPlugin::default_active_events`] to
+    /// [`ActiveCollisionEvents::empty()`] to avoid paying for the rest.
+    #[derive(Component, Resource, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+    pub struct ActiveCollisionEvents: u8 {
+        /// Enables [`CollisionStarted`] events.
+        const COLLISION_STARTED = 1 << 0;
+        /// Enables [`CollisionEnded`] events.
+        const COLLISION_ENDED = 1 << 1;
+        /// Enables [`Collision`] events.
+        const CONTACT = 1 << 2;
+        /// Enables [`ContactForceEvent`] events.
+        const CONTACT_FORCE = 1 << 3;
+    }
+}
+
+impl Default for ActiveCollisionEvents {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
 /// Sends collision events and updates [`CollidingEntities`].
 pub fn report_contacts(
+    mut commands: Commands,
     mut colliders: Query<&mut CollidingEntities>,
+    active_events: Query<&ActiveCollisionEvents>,
+    default_active_events: Res<ActiveCollisionEvents>,
+    force_thresholds: Query<&ContactForceEventThreshold>,
+    default_force_threshold: Res<ContactForceEventThreshold>,
     collisions: Res<Collisions>,
+    time: Res<Time<Substeps>>,
     mut collision_ev_writer: EventWriter<Collision>,
     mut collision_started_ev_writer: EventWriter<CollisionStarted>,
     mut collision_ended_ev_writer: EventWriter<CollisionEnded>,
+    mut contact_force_ev_writer: EventWriter<ContactForceEvent>,
     mut diagnostics: ResMut<CollisionDiagnostics>,
 ) {
     let start = crate::utils::Instant::now();
 
+    let delta_seconds = time.delta_seconds();
+
     // TODO: Would batching events be worth it?
     for ((entity1, entity2), contacts) in collisions.get_internal().iter() {
+        let events1 = active_events
+            .get(*entity1)
+            .copied()
+            .unwrap_or(*default_active_events);
+        let events2 = active_events
+            .get(*entity2)
+            .copied()
+            .unwrap_or(*default_active_events);
+        let pair_events = events1 | events2;
+
         if contacts.during_current_frame {
-            collision_ev_writer.write(Collision(contacts.clone()));
+            if pair_events.contains(ActiveCollisionEvents::CONTACT) {
+                collision_ev_writer.write(Collision(contacts.clone()));
+
+                commands.trigger_targets(
+                    OnContact {
+                        collider: *entity2,
+                        contacts: contacts.clone(),
+                    },
+                    *entity1,
+                );
+                commands.trigger_targets(
+                    OnContact {
+                        collider: *entity1,
+                        contacts: contacts.clone(),
+                    },
+                    *entity2,
+                );
+            }
 
             // Collision started
             if !contacts.during_previous_frame {
-                collision_started_ev_writer.write(CollisionStarted(*entity1, *entity2));
+                if pair_events.contains(ActiveCollisionEvents::COLLISION_STARTED) {
+                    collision_started_ev_writer.write(CollisionStarted(*entity1, *entity2));
+
+                    commands.trigger_targets(
+                        OnCollisionStart {
+                            collider: *entity2,
+                            contacts: contacts.clone(),
+                        },
+                        *entity1,
+                    );
+                    commands.trigger_targets(
+                        OnCollisionStart {
+                            collider: *entity1,
+                            contacts: contacts.clone(),
+                        },
+                        *entity2,
+                    );
+                }
 
                 if let Ok(mut colliding_entities1) = colliders.get_mut(*entity1) {
                     colliding_entities1.insert(*entity2);
@@ -181,11 +478,51 @@ pub fn report_contacts(
                     colliding_entities2.insert(*entity1);
                 }
             }
+
+            if delta_seconds > 0.0 && pair_events.contains(ActiveCollisionEvents::CONTACT_FORCE) {
+                let threshold = match (
+                    force_thresholds.get(*entity1).ok(),
+                    force_thresholds.get(*entity2).ok(),
+                ) {
+                    (Some(t1), Some(t2)) => t1.0.min(t2.0),
+                    (Some(t), None) | (None, Some(t)) => t.0,
+                    (None, None) => default_force_threshold.0,
+                };
+
+                let (total_force, max_force_magnitude) =
+                    total_contact_force(contacts, delta_seconds);
+
+                if max_force_magnitude > threshold {
+                    contact_force_ev_writer.write(ContactForceEvent {
+                        entity1: *entity1,
+                        entity2: *entity2,
+                        total_force,
+                        max_force_magnitude,
+                    });
+                }
+            }
         }
 
         // Collision ended
         if !contacts.during_current_frame && contacts.during_previous_frame {
-            collision_ended_ev_writer.write(CollisionEnded(*entity1, *entity2));
+            if pair_events.contains(ActiveCollisionEvents::COLLISION_ENDED) {
+                collision_ended_ev_writer.write(CollisionEnded(*entity1, *entity2));
+
+                commands.trigger_targets(
+                    OnCollisionEnd {
+                        collider: *entity2,
+                        contacts: contacts.clone(),
+                    },
+                    *entity1,
+                );
+                commands.trigger_targets(
+                    OnCollisionEnd {
+                        collider: *entity1,
+                        contacts: contacts.clone(),
+                    },
+                    *entity2,
+                );
+            }
 
             if let Ok(mut colliding_entities1) = colliders.get_mut(*entity1) {
                 colliding_entities1.remove(entity2);
@@ -198,3 +535,23 @@ pub fn report_contacts(
 
     diagnostics.collision_events = start.elapsed();
 }
+
+/// Sums the per-point normal and friction impulses accumulated over the substep and converts
+/// them to an average force, returning the total force vector and the magnitude of the
+/// largest single contact point's force.
+fn total_contact_force(contacts: &Contacts, delta_seconds: Scalar) -> (Vector, Scalar) {
+    let mut total_force = Vector::ZERO;
+    let mut max_force_magnitude: Scalar = 0.0;
+
+    for manifold in contacts.manifolds.iter() {
+        for point in manifold.points.iter() {
+            let impulse = manifold.normal * point.normal_impulse + point.tangent_impulse;
+            let force = impulse / delta_seconds;
+
+            total_force += force;
+            max_force_magnitude = max_force_magnitude.max(force.length());
+        }
+    }
+
+    (total_force, max_force_magnitude)
+}